@@ -0,0 +1,74 @@
+//! Benchmarking setup for pallet-kitties
+
+use super::*;
+use crate::Pallet as Kitties;
+
+use frame_benchmarking::{account, benchmarks, whitelisted_caller};
+use frame_support::traits::Currency;
+use frame_system::RawOrigin;
+
+type BalanceOf<T> =
+	<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+
+/// Mints a kitty owned by `owner`, returning its id. Used to pre-populate `KittiesOwned`
+/// up to its worst-case length before benchmarking a call that touches it.
+fn setup_kitty<T: Config>(owner: &T::AccountId) -> T::Hash {
+	Kitties::<T>::mint(owner, None, None, None).expect("minting a kitty in benchmarks works")
+}
+
+benchmarks! {
+	create_kitty {
+		let caller: T::AccountId = whitelisted_caller();
+		// Worst case: the caller already owns MaxKittyOwned - 1 kitties, so this call's
+		// `try_push` lands at the bound.
+		for _ in 0..T::MaxKittyOwned::get().saturating_sub(1) {
+			setup_kitty::<T>(&caller);
+		}
+	}: _(RawOrigin::Signed(caller.clone()))
+	verify {
+		assert_eq!(Kitties::<T>::kitty_cnt(), T::MaxKittyOwned::get() as u64);
+	}
+
+	set_price {
+		let caller: T::AccountId = whitelisted_caller();
+		let kitty_id = setup_kitty::<T>(&caller);
+	}: _(RawOrigin::Signed(caller), kitty_id, Some(1_000u32.into()))
+
+	transfer {
+		let caller: T::AccountId = whitelisted_caller();
+		let recipient: T::AccountId = account("recipient", 0, 0);
+		// Worst case: the recipient already owns MaxKittyOwned - 1 kitties.
+		for _ in 0..T::MaxKittyOwned::get().saturating_sub(1) {
+			setup_kitty::<T>(&recipient);
+		}
+		let kitty_id = setup_kitty::<T>(&caller);
+	}: _(RawOrigin::Signed(caller), recipient, kitty_id)
+
+	buy_kitty {
+		let seller: T::AccountId = whitelisted_caller();
+		let buyer: T::AccountId = account("buyer", 0, 0);
+		let price: BalanceOf<T> = 1_000u32.into();
+		T::Currency::make_free_balance_be(&buyer, price * 2u32.into());
+
+		// Worst case: the buyer already owns MaxKittyOwned - 1 kitties.
+		for _ in 0..T::MaxKittyOwned::get().saturating_sub(1) {
+			setup_kitty::<T>(&buyer);
+		}
+		let kitty_id = setup_kitty::<T>(&seller);
+		Kitties::<T>::set_price(RawOrigin::Signed(seller.clone()).into(), kitty_id, Some(price))?;
+	}: _(RawOrigin::Signed(buyer), kitty_id, price)
+
+	breed_kitty {
+		let caller: T::AccountId = whitelisted_caller();
+		let kid1 = Kitties::<T>::mint(&caller, None, Some(Gender::Male), None)
+			.expect("minting a kitty in benchmarks works");
+		let kid2 = Kitties::<T>::mint(&caller, None, Some(Gender::Female), None)
+			.expect("minting a kitty in benchmarks works");
+		// Worst case: the caller already owns MaxKittyOwned - 1 kitties (parents included).
+		for _ in 0..T::MaxKittyOwned::get().saturating_sub(3) {
+			setup_kitty::<T>(&caller);
+		}
+	}: _(RawOrigin::Signed(caller), kid1, kid2)
+
+	impl_benchmark_test_suite!(Kitties, crate::mock::new_test_ext(), crate::mock::Test);
+}
@@ -1,6 +1,16 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
 pub use pallet::*;
+pub use weights::WeightInfo;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+pub mod weights;
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
 
 #[frame_support::pallet]
 pub mod pallet {
@@ -12,6 +22,7 @@ pub mod pallet {
 		transactional
 	};
 	use sp_io::hashing::blake2_128;
+	use sp_std::vec::Vec;
 
 	#[cfg(feature = "std")]
 	use serde::{Deserialize, Serialize};
@@ -27,6 +38,8 @@ pub mod pallet {
 		pub price: Option<BalanceOf<T>>,
 		pub gender: Gender,
 		pub owner: AccountOf<T>,
+		/// How many generations removed from a founder (generation 0) this Kitty is.
+		pub generation: u64,
 	}
 	// Enum declaration for Gender.
 	#[derive(Encode, Decode, Debug, Clone, PartialEq)]
@@ -63,6 +76,9 @@ pub mod pallet {
 
 		/// The type of Randomness we want to specify for this pallet.
 		type KittyRandomness: Randomness<Self::Hash, Self::BlockNumber>;
+
+		/// Weight information for the extrinsics in this pallet.
+		type WeightInfo: crate::weights::WeightInfo;
 	}
 
 	// Errors.
@@ -86,8 +102,10 @@ pub mod pallet {
 		KittyNotForSale,
 		/// Ensures that the buying price is greater than the asking price.
 		KittyBidPriceTooLow,
-		/// Ensures that an account has enough funds to purchase a Kitty. 
+		/// Ensures that an account has enough funds to purchase a Kitty.
 		NotEnoughBalance,
+		/// Cannot breed two Kitties of the same gender.
+		IncompatibleParents,
 
 	}
 
@@ -103,7 +121,7 @@ pub mod pallet {
 		PriceSet(T::AccountId, T::Hash, Option<BalanceOf<T>>),
 		/// A Kitty was sucessfully transferred. \[from, to, kitty_id\]
 		Transferred(T::AccountId, T::AccountId, T::Hash),
-		/// A Kitty was sucessfully bought. \[buyer, seller, kitty_id, bid_price\]
+		/// A Kitty was sucessfully bought. \[buyer, seller, kitty_id, sale_price\]
 		Bought(T::AccountId, T::AccountId, T::Hash, BalanceOf<T>),
 	}
 
@@ -125,20 +143,39 @@ pub mod pallet {
 	pub(super) type KittiesOwned<T: Config> =
 		StorageMap<_, Twox64Concat, T::AccountId, BoundedVec<T::Hash, T::MaxKittyOwned>, ValueQuery>;
 
-	// TODO Part IV: Our pallet's genesis configuration.
+	#[pallet::genesis_config]
+	pub struct GenesisConfig<T: Config> {
+		pub kitties: Vec<(T::AccountId, [u8; 16], Gender)>,
+	}
+
+	#[cfg(feature = "std")]
+	impl<T: Config> Default for GenesisConfig<T> {
+		fn default() -> Self {
+			Self { kitties: vec![] }
+		}
+	}
 
+	#[pallet::genesis_build]
+	impl<T: Config> GenesisBuild<T> for GenesisConfig<T> {
+		fn build(&self) {
+			for (acct, dna, gender) in &self.kitties {
+				Pallet::<T>::mint(acct, Some(*dna), Some(gender.clone()), None)
+					.expect("genesis kitty mint should not fail");
+			}
+		}
+	}
 
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
 		/// Create a new unique kitty.
 		///
 		/// The actual kitty creation is done in the `mint()` function.
-		#[pallet::weight(100)]
+		#[pallet::weight(T::WeightInfo::create_kitty())]
 		pub fn create_kitty(origin: OriginFor<T>) -> DispatchResult {
 			// ACTION #1: create_kitty
             let sender = ensure_signed(origin)?;
 
-            let kitty_id = Self::mint(&sender, None, None)?;
+            let kitty_id = Self::mint(&sender, None, None, None)?;
         
             // Logging to the console
 			// Doesn't work, I suspect I need to update some stuff through scripts/init.sh
@@ -155,13 +192,118 @@ pub mod pallet {
 		// 	Ok(())
 		// }
 
-		// TODO Part IV: set_price
+		/// Set the price for a Kitty.
+		///
+		/// Updates Kitty price and updates storage.
+		#[transactional]
+		#[pallet::weight(T::WeightInfo::set_price())]
+		pub fn set_price(
+			origin: OriginFor<T>,
+			kitty_id: T::Hash,
+			new_price: Option<BalanceOf<T>>,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+
+			ensure!(Self::is_kitty_owner(&kitty_id, &sender)?, <Error<T>>::NotKittyOwner);
+
+			let mut kitty = Self::kitties(&kitty_id).ok_or(<Error<T>>::KittyNotExist)?;
 
-		// TODO Part IV: transfer
+			kitty.price = new_price.clone();
+			<Kitties<T>>::insert(&kitty_id, kitty);
+
+			Self::deposit_event(Event::PriceSet(sender, kitty_id, new_price));
+
+			Ok(())
+		}
+
+		/// Transfer a kitty to another account.
+		///
+		/// Any account that holds a kitty can send it to another account. This resets the
+		/// asking price of the kitty, marking it not for sale.
+		#[transactional]
+		#[pallet::weight(T::WeightInfo::transfer())]
+		pub fn transfer(
+			origin: OriginFor<T>,
+			to: T::AccountId,
+			kitty_id: T::Hash,
+		) -> DispatchResult {
+			let from = ensure_signed(origin)?;
+
+			ensure!(from != to, <Error<T>>::TransferToSelf);
+			ensure!(Self::is_kitty_owner(&kitty_id, &from)?, <Error<T>>::NotKittyOwner);
+
+			Self::transfer_kitty_to(&kitty_id, &to)?;
+
+			Self::deposit_event(Event::Transferred(from, to, kitty_id));
+
+			Ok(())
+		}
+
+		/// Buy a saleable Kitty. The bid price provided from the buyer has to be equal or higher
+		/// than the ask price from the seller.
+		///
+		/// This resets the asking price of the kitty, marking it not for sale.
+		#[transactional]
+		#[pallet::weight(T::WeightInfo::buy_kitty())]
+		pub fn buy_kitty(
+			origin: OriginFor<T>,
+			kitty_id: T::Hash,
+			bid_price: BalanceOf<T>,
+		) -> DispatchResult {
+			let buyer = ensure_signed(origin)?;
 
-		// TODO Part IV: buy_kitty
+			let kitty = Self::kitties(&kitty_id).ok_or(<Error<T>>::KittyNotExist)?;
+			ensure!(kitty.owner != buyer, <Error<T>>::BuyerIsKittyOwner);
 
-		// TODO Part IV: breed_kitty
+			let ask_price = kitty.price.ok_or(<Error<T>>::KittyNotForSale)?;
+			ensure!(bid_price >= ask_price, <Error<T>>::KittyBidPriceTooLow);
+
+			ensure!(T::Currency::free_balance(&buyer) >= bid_price, <Error<T>>::NotEnoughBalance);
+
+			let seller = kitty.owner.clone();
+
+			// Payment happens before the ownership transfer so a failed transfer aborts the
+			// whole extrinsic and leaves storage untouched.
+			T::Currency::transfer(&buyer, &seller, ask_price, ExistenceRequirement::KeepAlive)?;
+
+			Self::transfer_kitty_to(&kitty_id, &buyer)?;
+
+			Self::deposit_event(Event::Bought(buyer, seller, kitty_id, ask_price));
+
+			Ok(())
+		}
+
+		/// Breed a Kitty.
+		///
+		/// Breed two kitties to create a new generation of Kitties.
+		#[transactional]
+		#[pallet::weight(T::WeightInfo::breed_kitty())]
+		pub fn breed_kitty(
+			origin: OriginFor<T>,
+			kid1: T::Hash,
+			kid2: T::Hash,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+
+			ensure!(Self::is_kitty_owner(&kid1, &sender)?, <Error<T>>::NotKittyOwner);
+			ensure!(Self::is_kitty_owner(&kid2, &sender)?, <Error<T>>::NotKittyOwner);
+
+			let parent1 = Self::kitties(&kid1).ok_or(<Error<T>>::KittyNotExist)?;
+			let parent2 = Self::kitties(&kid2).ok_or(<Error<T>>::KittyNotExist)?;
+
+			ensure!(parent1.gender != parent2.gender, <Error<T>>::IncompatibleParents);
+
+			let new_dna = Self::breed_dna(&kid1, &kid2)?;
+			let new_gender = Kitty::<T>::gender(T::Hashing::hash(&new_dna));
+			let new_generation = parent1.generation.max(parent2.generation) + 1;
+
+			let new_kitty_id =
+				Self::mint(&sender, Some(new_dna), Some(new_gender), Some(new_generation))?;
+
+			Self::deposit_event(Event::Created(sender, new_kitty_id));
+
+			Ok(())
+		}
 	}
 
 	// Helper function for Kitty struct
@@ -214,12 +356,14 @@ pub mod pallet {
             owner: &T::AccountId,
             dna: Option<[u8; 16]>,
             gender: Option<Gender>,
+            generation: Option<u64>,
         ) -> Result<T::Hash, Error<T>> {
             let kitty = Kitty::<T> {
             dna: dna.unwrap_or_else(Self::gen_dna),
             price: None,
             gender: gender.unwrap_or_else(Self::gen_gender),
             owner: owner.clone(),
+            generation: generation.unwrap_or(0),
             };
         
             let kitty_id = T::Hashing::hash_of(&kitty);
@@ -248,6 +392,36 @@ pub mod pallet {
 			}
 		}
 
-	// TODO Part IV: Write transfer_kitty_to
+		// Helper to transfer a Kitty from one account to another.
+		pub fn transfer_kitty_to(
+			kitty_id: &T::Hash,
+			to: &T::AccountId,
+		) -> DispatchResult {
+			let mut kitty = Self::kitties(&kitty_id).ok_or(<Error<T>>::KittyNotExist)?;
+
+			let prev_owner = kitty.owner.clone();
+
+			// Remove `kitty_id` from the `KittiesOwned` vector of `prev_owner`.
+			<KittiesOwned<T>>::try_mutate(&prev_owner, |owned| {
+				if let Some(ind) = owned.iter().position(|&id| id == *kitty_id) {
+					owned.swap_remove(ind);
+					return Ok(());
+				}
+				Err(())
+			}).map_err(|_| <Error<T>>::KittyNotExist)?;
+
+			// Update the kitty owner.
+			kitty.owner = to.clone();
+			// Reset the ask price so the kitty is not still listed for the new owner.
+			kitty.price = None;
+
+			<Kitties<T>>::insert(kitty_id, kitty);
+
+			<KittiesOwned<T>>::try_mutate(to, |vec| {
+				vec.try_push(*kitty_id)
+			}).map_err(|_| <Error<T>>::ExceedMaxKittyOwned)?;
+
+			Ok(())
+		}
 	}
 }
\ No newline at end of file
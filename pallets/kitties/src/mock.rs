@@ -0,0 +1,118 @@
+use crate as pallet_kitties;
+use frame_support::traits::{ConstU128, ConstU16, ConstU32, ConstU64, Randomness};
+use sp_core::H256;
+use sp_runtime::{
+	testing::Header,
+	traits::{BlakeTwo256, Hash, IdentityLookup},
+};
+
+pub type AccountId = u64;
+pub type Balance = u128;
+
+type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
+type Block = frame_system::mocking::MockBlock<Test>;
+
+frame_support::construct_runtime!(
+	pub enum Test where
+		Block = Block,
+		NodeBlock = Block,
+		UncheckedExtrinsic = UncheckedExtrinsic,
+	{
+		System: frame_system,
+		Balances: pallet_balances,
+		KittiesModule: pallet_kitties,
+	}
+);
+
+impl frame_system::Config for Test {
+	type BaseCallFilter = frame_support::traits::Everything;
+	type BlockWeights = ();
+	type BlockLength = ();
+	type DbWeight = ();
+	type Origin = Origin;
+	type Call = Call;
+	type Index = u64;
+	type BlockNumber = u64;
+	type Hash = H256;
+	type Hashing = BlakeTwo256;
+	type AccountId = AccountId;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type Header = Header;
+	type Event = Event;
+	type BlockHashCount = ConstU64<250>;
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = pallet_balances::AccountData<Balance>;
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type SystemWeightInfo = ();
+	type SS58Prefix = ConstU16<42>;
+	type OnSetCode = ();
+	type MaxConsumers = frame_support::traits::ConstU32<16>;
+}
+
+impl pallet_balances::Config for Test {
+	type MaxLocks = ConstU32<50>;
+	type MaxReserves = ();
+	type ReserveIdentifier = [u8; 8];
+	type Balance = Balance;
+	type Event = Event;
+	type DustRemoval = ();
+	type ExistentialDeposit = ConstU128<1>;
+	type AccountStore = System;
+	type WeightInfo = ();
+}
+
+thread_local! {
+	/// Bumped on every `random()` call so repeated calls within the same block (e.g. two
+	/// `create_kitty`s for the same owner) still get distinct output.
+	static RANDOM_NONCE: std::cell::RefCell<u64> = std::cell::RefCell::new(0);
+}
+
+/// Deterministic "randomness" source for tests — derives its output from the subject, the
+/// block number, and a call nonce rather than any real entropy, so kitty DNA/gender are
+/// reproducible across test runs while still varying per subject and per call.
+pub struct TestRandomness<T>(sp_std::marker::PhantomData<T>);
+
+impl<Output, T> Randomness<Output, T::BlockNumber> for TestRandomness<T>
+where
+	Output: codec::Decode + Default,
+	T: frame_system::Config,
+{
+	fn random(subject: &[u8]) -> (Output, T::BlockNumber) {
+		let nonce = RANDOM_NONCE.with(|n| {
+			let mut n = n.borrow_mut();
+			*n += 1;
+			*n
+		});
+		let seed = T::Hashing::hash_of(&(
+			subject,
+			frame_system::Pallet::<T>::block_number(),
+			nonce,
+		));
+		(
+			Output::decode(&mut seed.as_ref()).unwrap_or_default(),
+			frame_system::Pallet::<T>::block_number(),
+		)
+	}
+}
+
+impl pallet_kitties::Config for Test {
+	type Event = Event;
+	type Currency = Balances;
+	type MaxKittyOwned = ConstU32<3>;
+	type KittyRandomness = TestRandomness<Self>;
+	type WeightInfo = ();
+}
+
+pub fn new_test_ext() -> sp_io::TestExternalities {
+	let mut storage = frame_system::GenesisConfig::default().build_storage::<Test>().unwrap();
+
+	pallet_balances::GenesisConfig::<Test> {
+		balances: vec![(1, 1_000), (2, 1_000), (3, 1_000)],
+	}
+	.assimilate_storage(&mut storage)
+	.unwrap();
+
+	storage.into()
+}
@@ -0,0 +1,85 @@
+use crate::{mock::*, Error};
+use frame_support::{assert_noop, assert_ok};
+
+#[test]
+fn create_kitty_works() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(KittiesModule::create_kitty(Origin::signed(1)));
+
+		assert_eq!(KittiesModule::kitty_cnt(), 1);
+		assert_eq!(KittiesModule::kitties_owned(1).len(), 1);
+	});
+}
+
+#[test]
+fn set_price_fails_for_non_owner() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(KittiesModule::create_kitty(Origin::signed(1)));
+		let kitty_id = KittiesModule::kitties_owned(1)[0];
+
+		assert_noop!(
+			KittiesModule::set_price(Origin::signed(2), kitty_id, Some(500)),
+			Error::<Test>::NotKittyOwner
+		);
+	});
+}
+
+#[test]
+fn buy_kitty_works() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(KittiesModule::create_kitty(Origin::signed(1)));
+		let kitty_id = KittiesModule::kitties_owned(1)[0];
+
+		assert_ok!(KittiesModule::set_price(Origin::signed(1), kitty_id, Some(500)));
+		assert_ok!(KittiesModule::buy_kitty(Origin::signed(2), kitty_id, 500));
+
+		assert_eq!(Balances::free_balance(1), 1_500);
+		assert_eq!(Balances::free_balance(2), 500);
+
+		let kitty = KittiesModule::kitties(kitty_id).unwrap();
+		assert_eq!(kitty.owner, 2);
+		assert_eq!(kitty.price, None);
+	});
+}
+
+#[test]
+fn buy_kitty_fails_below_ask_price() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(KittiesModule::create_kitty(Origin::signed(1)));
+		let kitty_id = KittiesModule::kitties_owned(1)[0];
+		assert_ok!(KittiesModule::set_price(Origin::signed(1), kitty_id, Some(500)));
+
+		assert_noop!(
+			KittiesModule::buy_kitty(Origin::signed(2), kitty_id, 100),
+			Error::<Test>::KittyBidPriceTooLow
+		);
+	});
+}
+
+#[test]
+fn transfer_fails_to_self() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(KittiesModule::create_kitty(Origin::signed(1)));
+		let kitty_id = KittiesModule::kitties_owned(1)[0];
+
+		assert_noop!(
+			KittiesModule::transfer(Origin::signed(1), 1, kitty_id),
+			Error::<Test>::TransferToSelf
+		);
+	});
+}
+
+#[test]
+fn create_kitty_fails_when_exceeding_max_kitty_owned() {
+	new_test_ext().execute_with(|| {
+		// MaxKittyOwned is set to 3 in the mock runtime.
+		for _ in 0..3 {
+			assert_ok!(KittiesModule::create_kitty(Origin::signed(1)));
+		}
+
+		assert_noop!(
+			KittiesModule::create_kitty(Origin::signed(1)),
+			Error::<Test>::ExceedMaxKittyOwned
+		);
+	});
+}
@@ -0,0 +1,100 @@
+//! Weights for pallet_kitties.
+//!
+//! These are hand-authored placeholder weights, not output from the Substrate benchmarking
+//! CLI — this tree has no runnable node binary to benchmark against. The constants are
+//! conservative, rounded-up estimates based on the storage accesses each call actually makes
+//! (see the per-function comments below); they should be replaced by running
+//! `frame-benchmarking-cli` against the `benchmarking.rs` suite once this pallet is wired into
+//! a real runtime.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for pallet_kitties.
+pub trait WeightInfo {
+	fn create_kitty() -> Weight;
+	fn set_price() -> Weight;
+	fn transfer() -> Weight;
+	fn buy_kitty() -> Weight;
+	fn breed_kitty() -> Weight;
+}
+
+/// Placeholder weights for pallet_kitties, pending a real benchmarking run.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	// Storage: Kitties KittyCnt (r:1 w:1)
+	// Storage: Kitties KittiesOwned (r:1 w:1)
+	// Storage: Kitties Kitties (r:0 w:1)
+	fn create_kitty() -> Weight {
+		Weight::from_ref_time(33_000_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(T::DbWeight::get().writes(3 as u64))
+	}
+	// Storage: Kitties Kitties (r:2 w:1) -- one read from `is_kitty_owner`, one to load the
+	// kitty being mutated
+	fn set_price() -> Weight {
+		Weight::from_ref_time(20_000_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: Kitties Kitties (r:2 w:1) -- one read from `is_kitty_owner`, one from
+	// `transfer_kitty_to`'s own load
+	// Storage: Kitties KittiesOwned (r:2 w:2) -- one read+write per account touched by
+	// `transfer_kitty_to` (sender's swap_remove, recipient's try_push)
+	fn transfer() -> Weight {
+		Weight::from_ref_time(29_000_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(4 as u64))
+			.saturating_add(T::DbWeight::get().writes(3 as u64))
+	}
+	// Storage: Kitties Kitties (r:2 w:1) -- one read for the buyer's own load, one from
+	// `transfer_kitty_to`'s load
+	// Storage: Kitties KittiesOwned (r:2 w:2) -- one read+write per account touched by
+	// `transfer_kitty_to` (seller's swap_remove, buyer's try_push)
+	fn buy_kitty() -> Weight {
+		Weight::from_ref_time(37_000_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(4 as u64))
+			.saturating_add(T::DbWeight::get().writes(3 as u64))
+	}
+	// Storage: Kitties Kitties (r:6 w:1) -- two `is_kitty_owner` checks, two direct parent
+	// loads, and two more inside `breed_dna`, plus the one write from `mint`'s insert
+	// Storage: Kitties KittyCnt (r:1 w:1)
+	// Storage: Kitties KittiesOwned (r:1 w:1)
+	fn breed_kitty() -> Weight {
+		Weight::from_ref_time(45_000_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(8 as u64))
+			.saturating_add(T::DbWeight::get().writes(3 as u64))
+	}
+}
+
+// For backwards compatibility and tests.
+impl WeightInfo for () {
+	fn create_kitty() -> Weight {
+		Weight::from_ref_time(33_000_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(2 as u64))
+			.saturating_add(RocksDbWeight::get().writes(3 as u64))
+	}
+	fn set_price() -> Weight {
+		Weight::from_ref_time(20_000_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(2 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	fn transfer() -> Weight {
+		Weight::from_ref_time(29_000_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(4 as u64))
+			.saturating_add(RocksDbWeight::get().writes(3 as u64))
+	}
+	fn buy_kitty() -> Weight {
+		Weight::from_ref_time(37_000_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(4 as u64))
+			.saturating_add(RocksDbWeight::get().writes(3 as u64))
+	}
+	fn breed_kitty() -> Weight {
+		Weight::from_ref_time(45_000_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(8 as u64))
+			.saturating_add(RocksDbWeight::get().writes(3 as u64))
+	}
+}